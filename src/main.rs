@@ -1,12 +1,23 @@
 use std::io;
 use std::path::Path;
 
+#[cfg(feature = "solana")]
+use std::{fs, io::Write};
+
 // Import Solana dependencies when in Solana mode
 #[cfg(feature = "solana")]
 use {
     solana_client::rpc_client::RpcClient,
     solana_sdk::pubkey::Pubkey,
-    solana_sdk::signature::{Keypair, read_keypair_file, Signer},
+    solana_account_decoder::UiAccountData,
+    solana_client::rpc_request::TokenAccountsFilter,
+    solana_sdk::signature::{Keypair, Signature, read_keypair_file, Signer},
+    solana_sdk::system_instruction,
+    solana_sdk::transaction::Transaction,
+    solana_transaction_status::UiTransactionEncoding,
+    std::str::FromStr,
+    std::thread,
+    std::time::Duration,
 };
 
 // Struct definition for Rust demo
@@ -127,14 +138,30 @@ fn solana_interaction_demo() {
         // Get the default keypair path
         let default_keypair_path = shellexpand::tilde("~/.config/solana/id.json").to_string();
         
-        // Load the wallet keypair
+        // Load the wallet keypair, offering to generate one if it doesn't exist yet
         let keypair_path = Path::new(&default_keypair_path);
         let keypair = match read_keypair_file(keypair_path) {
             Ok(kp) => kp,
             Err(_) => {
-                println!("Failed to read keypair from {}", default_keypair_path);
-                println!("Make sure you've created a wallet using 'solana-keygen new'");
-                return;
+                if keypair_path.exists() {
+                    println!("Failed to read keypair from {}", default_keypair_path);
+                    println!("Make sure you've created a wallet using 'solana-keygen new'");
+                    return;
+                }
+                println!("No keypair found at {}", default_keypair_path);
+                let response = get_user_input("Generate a new wallet now? (y/n): ");
+                if response.trim().eq_ignore_ascii_case("y") {
+                    match generate_and_save_keypair(keypair_path) {
+                        Ok(kp) => kp,
+                        Err(err) => {
+                            println!("Failed to generate keypair: {}", err);
+                            return;
+                        }
+                    }
+                } else {
+                    println!("Make sure you've created a wallet using 'solana-keygen new'");
+                    return;
+                }
             }
         };
         
@@ -179,20 +206,280 @@ fn solana_interaction_demo() {
                 println!("Failed to get transaction history: {}", err);
             }
         }
+
+        // Actions submenu
+        loop {
+            println!("\nSolana Actions:");
+            println!("1. Request Airdrop");
+            println!("2. Send SOL (Pay)");
+            println!("3. Confirm Transaction (verbose)");
+            println!("4. List SPL Token Balances");
+            println!("5. Back to main menu");
+
+            let choice = get_user_input("Enter your choice (1-5): ");
+
+            match choice.trim() {
+                "1" => {
+                    let amount_str = get_user_input("Enter SOL amount to airdrop: ");
+                    match amount_str.trim().parse::<f64>() {
+                        Ok(amount) => request_airdrop(&client, &pubkey, amount),
+                        Err(_) => println!("Invalid SOL amount."),
+                    }
+                },
+                "2" => {
+                    let recipient_str = get_user_input("Enter recipient address: ");
+                    let amount_str = get_user_input("Enter SOL amount to send: ");
+                    match (Pubkey::from_str(recipient_str.trim()), amount_str.trim().parse::<f64>()) {
+                        (Ok(recipient), Ok(amount)) => {
+                            pay(&client, &keypair, &recipient, amount);
+                        },
+                        (Err(_), _) => println!("Invalid recipient address."),
+                        (_, Err(_)) => println!("Invalid SOL amount."),
+                    }
+                },
+                "3" => {
+                    let sig_str = get_user_input("Enter transaction signature: ");
+                    match Signature::from_str(sig_str.trim()) {
+                        Ok(signature) => confirm_transaction_verbose(&client, &signature),
+                        Err(_) => println!("Invalid signature."),
+                    }
+                },
+                "4" => list_token_balances(&client, &pubkey),
+                "5" => break,
+                _ => println!("Invalid choice. Please select 1, 2, 3, 4, or 5."),
+            }
+        }
     }
-    
+
     #[cfg(not(feature = "solana"))]
     {
         println!("Solana features are not enabled. To use Solana features:");
         println!("1. Add these dependencies to your Cargo.toml:");
         println!("   solana-sdk = \"1.17.0\"");
         println!("   solana-client = \"1.17.0\"");
+        println!("   solana-program = \"1.17.0\"");
+        println!("   solana-account-decoder = \"1.17.0\"");
+        println!("   solana-transaction-status = \"1.17.0\"");
+        println!("   spl-token = \"4.0.0\"");
         println!("   shellexpand = \"3.1.0\"");
+        println!("   serde_json = \"1.0\"");
+        println!("   chrono = \"0.4\"");
         println!("2. Run cargo with --features=\"solana\"");
         println!("   Or add [features] section to Cargo.toml: solana = []");
     }
 }
 
+// Request a devnet airdrop and wait for it to confirm
+#[cfg(feature = "solana")]
+fn request_airdrop(client: &RpcClient, pubkey: &Pubkey, amount_sol: f64) {
+    let lamports = (amount_sol * 1_000_000_000.0) as u64;
+
+    let signature = match client.request_airdrop(pubkey, lamports) {
+        Ok(sig) => sig,
+        Err(err) => {
+            println!("Failed to request airdrop: {}", err);
+            return;
+        }
+    };
+
+    println!("Airdrop requested, signature: {}", signature);
+    println!("Waiting for confirmation...");
+
+    let timeout = Duration::from_secs(30);
+    let poll_interval = Duration::from_millis(500);
+    let start = std::time::Instant::now();
+
+    loop {
+        match client.confirm_transaction(&signature) {
+            Ok(true) => {
+                println!("Airdrop confirmed!");
+                break;
+            },
+            Ok(false) => {
+                if start.elapsed() >= timeout {
+                    println!("Timed out waiting for airdrop confirmation.");
+                    return;
+                }
+                thread::sleep(poll_interval);
+            },
+            Err(err) => {
+                println!("Error checking confirmation status: {}", err);
+                return;
+            }
+        }
+    }
+
+    match client.get_balance(pubkey) {
+        Ok(balance) => println!("Updated balance: {} SOL", balance as f64 / 1_000_000_000.0),
+        Err(err) => println!("Failed to get updated balance: {}", err),
+    }
+}
+
+// Build, sign, and send a SOL transfer
+#[cfg(feature = "solana")]
+fn pay(client: &RpcClient, from: &Keypair, to: &Pubkey, amount_sol: f64) {
+    let lamports = (amount_sol * 1_000_000_000.0) as u64;
+
+    // Fetch a fresh blockhash at send time rather than reusing the one
+    // captured when the submenu was entered, which may have since expired
+    let recent_blockhash = match client.get_latest_blockhash() {
+        Ok(hash) => hash,
+        Err(err) => {
+            println!("Failed to get recent blockhash: {}", err);
+            return;
+        }
+    };
+
+    let instruction = system_instruction::transfer(&from.pubkey(), to, lamports);
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&from.pubkey()),
+        &[from],
+        recent_blockhash,
+    );
+
+    match client.send_and_confirm_transaction(&tx) {
+        Ok(signature) => println!("Transfer sent! Signature: {}", signature),
+        Err(err) => println!("Failed to send transfer: {}", err),
+    }
+}
+
+// Fetch a confirmed transaction and print it in a verbose, `solana confirm -v` style layout
+#[cfg(feature = "solana")]
+fn confirm_transaction_verbose(client: &RpcClient, signature: &Signature) {
+    let tx = match client.get_transaction(signature, UiTransactionEncoding::Json) {
+        Ok(tx) => tx,
+        Err(err) => {
+            println!("Failed to fetch transaction: {}", err);
+            return;
+        }
+    };
+
+    println!("\nTransaction: {}", signature);
+    println!("Slot: {}", tx.slot);
+
+    match tx.block_time {
+        Some(timestamp) => println!("Block time: {}", format_unix_timestamp(timestamp)),
+        None => println!("Block time: unknown"),
+    }
+
+    let meta = match tx.transaction.meta {
+        Some(meta) => meta,
+        None => {
+            println!("No metadata available for this transaction.");
+            return;
+        }
+    };
+
+    println!("Fee: {} SOL", meta.fee as f64 / 1_000_000_000.0);
+
+    match &meta.err {
+        Some(err) => println!("Status: Error - {:?}", err),
+        None => println!("Status: Success"),
+    }
+
+    println!("\nAccount Balances:");
+    for (i, (pre, post)) in meta.pre_balances.iter().zip(meta.post_balances.iter()).enumerate() {
+        let delta = *post as i64 - *pre as i64;
+        println!(
+            "  Account {}: {} -> {} SOL (delta: {:+} SOL)",
+            i,
+            *pre as f64 / 1_000_000_000.0,
+            *post as f64 / 1_000_000_000.0,
+            delta as f64 / 1_000_000_000.0
+        );
+    }
+
+    if let Some(log_messages) = Option::<Vec<String>>::from(meta.log_messages) {
+        println!("\nLog Messages:");
+        for log in log_messages {
+            println!("  {}", log);
+        }
+    }
+}
+
+// Convert a Unix timestamp into a human-readable UTC date/time string
+#[cfg(feature = "solana")]
+fn format_unix_timestamp(timestamp: i64) -> String {
+    let datetime = chrono::DateTime::from_timestamp(timestamp, 0)
+        .unwrap_or_default();
+    datetime.format("%Y-%m-%d %H:%M:%S UTC").to_string()
+}
+
+// List the owner's SPL token accounts and decode their balances
+#[cfg(feature = "solana")]
+fn list_token_balances(client: &RpcClient, owner: &Pubkey) {
+    let accounts = match client.get_token_accounts_by_owner(
+        owner,
+        TokenAccountsFilter::ProgramId(spl_token::id()),
+    ) {
+        Ok(accounts) => accounts,
+        Err(err) => {
+            println!("Failed to fetch token accounts: {}", err);
+            return;
+        }
+    };
+
+    if accounts.is_empty() {
+        println!("No SPL token accounts found.");
+        return;
+    }
+
+    println!("\n{:<46} {:>20}", "Mint", "Balance");
+    for keyed_account in accounts {
+        // get_token_accounts_by_owner already returns JsonParsed data, which
+        // carries mint, decimals, and UI amount without a second RPC round-trip
+        let parsed_account = match &keyed_account.account.data {
+            UiAccountData::Json(parsed) => parsed,
+            _ => {
+                println!("{:<46} {:>20}", keyed_account.pubkey, "<unparsed account>");
+                continue;
+            }
+        };
+
+        let info = &parsed_account.parsed["info"];
+        let mint = match info["mint"].as_str() {
+            Some(mint) => mint,
+            None => {
+                println!("{:<46} {:>20}", keyed_account.pubkey, "<failed to decode mint>");
+                continue;
+            }
+        };
+
+        let ui_amount = match info["tokenAmount"]["uiAmount"].as_f64() {
+            Some(amount) => amount,
+            None => {
+                println!("{:<46} {:>20}", mint, "<failed to decode balance>");
+                continue;
+            }
+        };
+
+        println!("{:<46} {:>20}", mint, ui_amount);
+    }
+}
+
+// Generate a new keypair and persist it to `path` in the JSON array format
+// that `read_keypair_file` expects, creating the parent directory if needed
+#[cfg(feature = "solana")]
+fn generate_and_save_keypair(path: &Path) -> io::Result<Keypair> {
+    let keypair = Keypair::new();
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let bytes = keypair.to_bytes().to_vec();
+    let json = serde_json::to_string(&bytes)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut file = fs::File::create(path)?;
+    file.write_all(json.as_bytes())?;
+
+    println!("Generated new wallet. Public key: {}", keypair.pubkey());
+    println!("Keypair saved to {}", path.display());
+
+    Ok(keypair)
+}
+
 // Helper functions for Rust basics demo
 fn take_ownership(s: String) {
     println!("  Took ownership of: {}", s);